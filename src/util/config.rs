@@ -1,29 +1,212 @@
 use std::cell::{RefCell, Ref};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::{done, fail, fatal, info, warn};
 
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum Platform {
+	Win,
+	Mac,
+	Android32,
+	Android64,
+	Linux,
+}
+
+impl Platform {
+	/// The platform this binary was built for, used as the default for
+	/// profiles created without an explicit target (e.g. local dev installs).
+	pub fn host() -> Platform {
+		#[cfg(windows)]
+		return Platform::Win;
+		#[cfg(target_os = "macos")]
+		return Platform::Mac;
+		#[cfg(target_os = "linux")]
+		return Platform::Linux;
+		#[cfg(not(any(windows, target_os = "macos", target_os = "linux")))]
+		{
+			use std::compile_error;
+			compile_error!("implement host platform");
+		}
+	}
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct Profile {
 	pub name: String,
 	pub gd_path: PathBuf,
+	pub platform: Platform,
 
 	#[serde(flatten)]
 	other: HashMap<String, Value>,
 }
 
+/// A parsed `MAJOR.MINOR.PATCH` SDK release tag. Ordered so the catalog can
+/// keep the available releases sorted without caring how they arrived.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct SdkVersion {
+	pub major: u32,
+	pub minor: u32,
+	pub patch: u32,
+}
+
+impl SdkVersion {
+	pub fn parse(tag: &str) -> Option<SdkVersion> {
+		let mut parts = tag.strip_prefix('v').unwrap_or(tag).split('.');
+		Some(SdkVersion {
+			major: parts.next()?.parse().ok()?,
+			minor: parts.next()?.parse().ok()?,
+			patch: parts.next()?.parse().ok()?,
+		})
+	}
+}
+
+impl std::fmt::Display for SdkVersion {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+	}
+}
+
+/// The SDK build a profile/install is pinned to: either a released version
+/// or the rolling `Nightly` pseudo-version.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum SdkSelection {
+	Version(SdkVersion),
+	Nightly,
+}
+
+impl SdkSelection {
+	/// Where the binaries for this selection are installed under
+	/// `geode_root()/bin`.
+	pub fn bin_dir(&self) -> PathBuf {
+		let bin = geode_root().join("bin");
+		match self {
+			SdkSelection::Version(version) => bin.join(version.to_string()),
+			SdkSelection::Nightly => bin.join("nightly"),
+		}
+	}
+}
+
+/// Tracks which SDK binary version is currently active and which versions
+/// are available to install with `geode sdk install --version`.
+///
+/// The available-versions list is lazy: nothing is fetched until
+/// [`SdkCatalog::available_versions`] is first called, and the result is
+/// then memoized both in memory and in a cache file under `geode_root()`
+/// so later runs don't need to hit the network either.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct SdkCatalog {
+	pub selected: Option<SdkSelection>,
+
+	#[serde(skip)]
+	available: RefCell<Option<BTreeSet<SdkVersion>>>,
+}
+
+impl SdkCatalog {
+	fn releases_cache_path() -> PathBuf {
+		geode_root().join("sdk-releases.json")
+	}
+
+	/// The sorted set of SDK versions that can be installed. Only fetches
+	/// the remote releases index on the first call (or the first call
+	/// after the on-disk cache is cleared); every later call, in this
+	/// process or a future one, reuses the memoized result.
+	pub fn available_versions(&self) -> BTreeSet<SdkVersion> {
+		if let Some(cached) = self.available.borrow().as_ref() {
+			return cached.clone();
+		}
+
+		let tags = std::fs::read_to_string(Self::releases_cache_path())
+			.ok()
+			.and_then(|cached| serde_json::from_str::<Vec<String>>(&cached).ok());
+
+		let tags = match tags {
+			Some(tags) => tags,
+			None => match fetch_release_tags() {
+				Ok(tags) => {
+					if let Ok(raw) = serde_json::to_string(&tags) {
+						let _ = std::fs::create_dir_all(geode_root());
+						let _ = std::fs::write(Self::releases_cache_path(), raw);
+					}
+					tags
+				}
+				Err(e) => {
+					warn!("{}", e);
+					Vec::new()
+				}
+			},
+		};
+
+		let versions: BTreeSet<SdkVersion> =
+			tags.iter().filter_map(|tag| SdkVersion::parse(tag)).collect();
+
+		*self.available.borrow_mut() = Some(versions.clone());
+		versions
+	}
+}
+
+/// The GitHub releases index the SDK catalog's available versions are
+/// fetched from.
+const GEODE_RELEASES_URL: &str = "https://api.github.com/repos/geode-sdk/geode/releases";
+
+fn fetch_release_tags() -> Result<Vec<String>, String> {
+	let mut tags = Vec::new();
+	let mut url = format!("{}?per_page=100", GEODE_RELEASES_URL);
+
+	loop {
+		let response = ureq::get(&url)
+			.call()
+			.map_err(|e| format!("Unable to fetch SDK releases: {}", e))?;
+
+		let next = next_page_url(response.header("Link"));
+
+		let releases: Vec<Value> = response
+			.into_json()
+			.map_err(|e| format!("Unable to parse SDK releases: {}", e))?;
+
+		tags.extend(
+			releases
+				.iter()
+				.filter_map(|release| release.get("tag_name")?.as_str().map(str::to_owned)),
+		);
+
+		match next {
+			Some(next) => url = next,
+			None => break,
+		}
+	}
+
+	Ok(tags)
+}
+
+/// Pulls the `rel="next"` URL out of a GitHub `Link` response header, if
+/// there's another page of results to fetch.
+fn next_page_url(link_header: Option<&str>) -> Option<String> {
+	link_header?.split(',').find_map(|link| {
+		let (url, rel) = link.split_once(';')?;
+		if rel.trim() == "rel=\"next\"" {
+			Some(url.trim().trim_start_matches('<').trim_end_matches('>').to_owned())
+		} else {
+			None
+		}
+	})
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct Config {
+	pub config_version: u32,
 	pub current_profile: Option<String>,
 	pub profiles: Vec<RefCell<Profile>>,
 	pub default_developer: Option<String>,
-	pub sdk_nightly: bool,
+	pub sdk_catalog: SdkCatalog,
 	#[serde(flatten)]
 	other: HashMap<String, Value>,
 }
@@ -55,13 +238,19 @@ impl OldConfig {
 				insts
 					.iter()
 					.map(|inst| {
+						let platform = if inst.executable.ends_with(".exe") {
+							Platform::Win
+						} else {
+							Platform::host()
+						};
 						RefCell::from(Profile {
 							name: inst
 								.executable
 								.strip_suffix(".exe")
 								.unwrap_or(&inst.executable)
 								.into(),
-							gd_path: inst.path.clone(),
+							gd_path: locate_gd_binary(&inst.path, platform),
+							platform,
 							other: HashMap::new(),
 						})
 					})
@@ -69,6 +258,7 @@ impl OldConfig {
 			})
 			.unwrap_or_default();
 		Config {
+			config_version: CONFIG_VERSION,
 			current_profile: profiles
 				.get(
 					self.working_installation
@@ -77,19 +267,228 @@ impl OldConfig {
 				.map(|i| i.borrow().name.clone()),
 			profiles,
 			default_developer: self.default_developer.to_owned(),
-			sdk_nightly: false,
+			sdk_catalog: SdkCatalog::default(),
 			other: HashMap::new(),
 		}
 	}
 }
 
+/// The current on-disk `config.json` schema version. Bump this and append
+/// a step to `MIGRATIONS` whenever the schema changes.
+const CONFIG_VERSION: u32 = 3;
+
+/// A single schema migration: takes the raw JSON as stored under the
+/// previous version and returns it reshaped for the next one. Kept as a
+/// pure `fn(Value) -> Value` so each step can be unit tested in isolation
+/// without going through the full `Config::new` load path.
+type Migration = fn(Value) -> Value;
+
+/// Migrations in order, indexed by the version they migrate *from*.
+/// `MIGRATIONS[0]` takes a v0 (legacy/unversioned) config to v1, and so on.
+const MIGRATIONS: &[Migration] = &[migrate_v0_to_v1, migrate_v1_to_v2, migrate_v2_to_v3];
+
+/// v0 -> v1: introduces `config-version` itself. Absorbs the old ad-hoc
+/// `OldConfig` fallback (installations-based configs) so it becomes just
+/// another step in the chain instead of a separate try-parse cascade.
+fn migrate_v0_to_v1(value: Value) -> Value {
+	let Value::Object(map) = value else {
+		return value;
+	};
+
+	let mut map = if map.contains_key("installations") {
+		match serde_json::from_value::<OldConfig>(Value::Object(map.clone())) {
+			Ok(old) => match serde_json::to_value(old.migrate()) {
+				Ok(Value::Object(migrated)) => migrated,
+				_ => map,
+			},
+			Err(_) => map,
+		}
+	} else {
+		map
+	};
+
+	map.insert("config-version".into(), Value::from(1));
+	Value::Object(map)
+}
+
+/// v1 -> v2: profiles used to store the folder that directly contained
+/// `geode/`; they now store the actual GD executable/bundle location so
+/// `Profile::geode_dir` can derive the right layout per platform.
+fn migrate_v1_to_v2(value: Value) -> Value {
+	let Value::Object(mut map) = value else {
+		return value;
+	};
+
+	if let Some(Value::Array(profiles)) = map.get_mut("profiles") {
+		for profile in profiles.iter_mut() {
+			let Value::Object(profile) = profile else {
+				continue;
+			};
+
+			let platform = profile
+				.get("platform")
+				.and_then(|p| serde_json::from_value::<Platform>(p.clone()).ok())
+				.unwrap_or_else(Platform::host);
+
+			// Baseline (pre-chunk0-1) configs never had this field; backfill
+			// it regardless of whether it was already present, since
+			// `Profile::platform` has no serde default and its absence
+			// would otherwise fail the final parse.
+			profile.insert(
+				"platform".into(),
+				serde_json::to_value(platform).expect("Platform always serializes"),
+			);
+
+			if let Some(Value::String(path)) = profile.get("gd-path") {
+				// `OldConfig::migrate` (run as part of `migrate_v0_to_v1`)
+				// already resolves legacy `installations` entries to a
+				// binary-style path, so only re-locate here for profiles
+				// that still point at the old folder-style layout.
+				let already_binary = match platform {
+					Platform::Win => Path::new(path).extension().is_some_and(|ext| ext == "exe"),
+					Platform::Mac => Path::new(path).extension().is_some_and(|ext| ext == "app"),
+					Platform::Linux | Platform::Android32 | Platform::Android64 => false,
+				};
+
+				if !already_binary {
+					let upgraded = locate_gd_binary(Path::new(path), platform);
+					profile.insert(
+						"gd-path".into(),
+						Value::String(upgraded.to_string_lossy().into_owned()),
+					);
+				}
+			}
+		}
+	}
+
+	map.insert("config-version".into(), Value::from(2));
+	Value::Object(map)
+}
+
+/// v2 -> v3: replaces the single `sdk-nightly` flag with `sdk-catalog`, so
+/// a selected SDK version (rather than just "nightly or not") can be
+/// tracked alongside the versions available to install.
+fn migrate_v2_to_v3(value: Value) -> Value {
+	let Value::Object(mut map) = value else {
+		return value;
+	};
+
+	let was_nightly = map
+		.remove("sdk-nightly")
+		.is_some_and(|v| v.as_bool().unwrap_or(false));
+
+	map.insert(
+		"sdk-catalog".into(),
+		serde_json::json!({
+			"selected": if was_nightly { Value::from("nightly") } else { Value::Null },
+		}),
+	);
+
+	map.insert("config-version".into(), Value::from(3));
+	Value::Object(map)
+}
+
+/// Given the old folder-style `gd_path` (a directory that directly
+/// contained `geode/`), attempts to locate the actual GD executable/bundle
+/// inside it so a profile can be upgraded to the new binary-style path.
+/// Falls back to the folder unchanged if nothing matching the platform was
+/// found (with a warning on Windows and macOS; Linux and Android are never
+/// searched and keep the folder silently).
+fn locate_gd_binary(folder: &Path, platform: Platform) -> PathBuf {
+	match platform {
+		Platform::Win => {
+			let exe = folder.join("GeometryDash.exe");
+			if exe.exists() {
+				return exe;
+			}
+			warn!(
+				"Could not locate the GD binary inside '{}' while migrating; \
+				keeping the old path. You may need to update this profile manually.",
+				folder.display()
+			);
+		}
+		Platform::Mac => {
+			if let Ok(entries) = std::fs::read_dir(folder) {
+				for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+					if path.extension().is_some_and(|ext| ext == "app") {
+						return path;
+					}
+				}
+			}
+			warn!(
+				"Could not locate the GD bundle inside '{}' while migrating; \
+				keeping the old path. You may need to update this profile manually.",
+				folder.display()
+			);
+		}
+		// Geode has no canonical binary/launcher path to locate on these
+		// platforms yet, so `gd_path` stays the folder that directly
+		// contains `geode/`; `Profile::geode_dir` knows to resolve relative
+		// to the stored path itself rather than its parent for them.
+		Platform::Linux | Platform::Android32 | Platform::Android64 => {}
+	}
+
+	folder.to_owned()
+}
+
+/// Reads `config-version`, defaulting to `0` for configs predating the field.
+fn config_version_of(value: &Value) -> u32 {
+	value
+		.get("config-version")
+		.and_then(Value::as_u64)
+		.map(|v| v as u32)
+		.unwrap_or(0)
+}
+
+/// Runs every migration needed to bring `value` up to `CONFIG_VERSION`,
+/// backing up the original file first so a failed migration can be
+/// recovered from by hand.
+fn migrate_config(value: Value, config_json: &Path, raw: &str) -> Value {
+	let version = config_version_of(&value);
+	if version >= CONFIG_VERSION {
+		return value;
+	}
+
+	let bak = config_json.with_extension("json.bak");
+	if !bak.exists() {
+		std::fs::write(&bak, raw)
+			.unwrap_or_else(|e| warn!("Unable to back up config.json before migrating: {}", e));
+	}
+
+	MIGRATIONS
+		.iter()
+		.skip(version as usize)
+		.fold(value, |value, step| step(value))
+}
+
 pub fn geode_root() -> PathBuf {
+	// GEODE_ROOT always takes precedence, for portable installs and other
+	// non-standard layouts
+	if let Ok(root) = std::env::var("GEODE_ROOT") {
+		let root = PathBuf::from(root);
+		if !root.is_dir() {
+			fatal!(
+				"GEODE_ROOT is set to '{}', which is not an existing directory",
+				root.display()
+			);
+		}
+		return root;
+	}
+
 	// get data dir per-platform
 	let data_dir: PathBuf;
-	#[cfg(any(windows, target_os = "linux"))]
+	#[cfg(windows)]
 	{
 		data_dir = dirs::data_local_dir().unwrap().join("Geode");
 	};
+	#[cfg(target_os = "linux")]
+	{
+		// honor XDG_DATA_HOME before falling back to the default data dir
+		data_dir = std::env::var_os("XDG_DATA_HOME")
+			.map(PathBuf::from)
+			.unwrap_or_else(|| dirs::data_local_dir().unwrap())
+			.join("Geode");
+	};
 	#[cfg(target_os = "macos")]
 	{
 		data_dir = PathBuf::from("/Users/Shared/Geode");
@@ -103,16 +502,40 @@ pub fn geode_root() -> PathBuf {
 }
 
 impl Profile {
-	pub fn new(name: String, location: PathBuf) -> Profile {
+	pub fn new(name: String, location: PathBuf, platform: Platform) -> Profile {
 		Profile {
 			name,
 			gd_path: location,
+			platform,
 			other: HashMap::<String, Value>::new(),
 		}
 	}
 
+	/// The directory Geode's own data (mods, index, config) lives in. On
+	/// Windows this sits next to the GD executable; on macOS it lives inside
+	/// the `.app` bundle if Geode already put it there, otherwise alongside
+	/// the bundle. On Linux and Android there's no canonical GD binary to
+	/// locate, so `gd_path` is itself the folder Geode's data sits in.
 	pub fn geode_dir(&self) -> PathBuf {
-		self.gd_path.join("geode")
+		if self.platform == Platform::Mac {
+			let inside = self.gd_path.join("Contents/geode");
+			if inside.exists() {
+				return inside;
+			}
+		}
+
+		if matches!(
+			self.platform,
+			Platform::Linux | Platform::Android32 | Platform::Android64
+		) {
+			return self.gd_path.join("geode");
+		}
+
+		self
+			.gd_path
+			.parent()
+			.map(|dir| dir.join("geode"))
+			.unwrap_or_else(|| self.gd_path.join("geode"))
 	}
 
 	pub fn index_dir(&self) -> PathBuf {
@@ -140,7 +563,17 @@ impl Config {
 			.borrow()
 	}
 
-	pub fn try_sdk_path() -> Result<PathBuf, &'static str> {
+	/// The active SDK binary directory: whichever version `sdk_catalog`
+	/// has selected (installed via `geode sdk install --version`), falling
+	/// back to the `GEODE_SDK` env var for setups that predate the catalog.
+	pub fn try_sdk_path(&self) -> Result<PathBuf, &'static str> {
+		if let Some(selected) = &self.sdk_catalog.selected {
+			let path = selected.bin_dir();
+			if path.is_dir() {
+				return Ok(path);
+			}
+		}
+
 		let sdk_var = std::env::var("GEODE_SDK")
 			.map_err(|_|
 				"Unable to find Geode SDK (GEODE_SDK isn't set). Please install \
@@ -149,7 +582,7 @@ impl Config {
 				`geode sdk install`, please restart your terminal / computer to \
 				apply changes."
 			)?;
-	
+
 		let path = PathBuf::from(sdk_var);
 		if !path.is_dir() {
 			return Err("Internal Error: GEODE_SDK doesn't point to a directory. Fix it manually or reinstall using `geode sdk install --reinstall`");
@@ -159,12 +592,12 @@ impl Config {
 				"Internal Error: GEODE_SDK/VERSION not found. Please reinstall the Geode SDK using `geode sdk install --reinstall`"
 			);
 		}
-	
+
 		Ok(path)
 	}
 
-	pub fn sdk_path() -> PathBuf {
-		match Self::try_sdk_path() {
+	pub fn sdk_path(&self) -> PathBuf {
+		match self.try_sdk_path() {
 			Ok(path) => path,
 			Err(err) => {
 				fatal!("{}", err);
@@ -178,10 +611,11 @@ impl Config {
 			warn!("You can setup Geode using `geode config setup`");
 
 			return Config {
+				config_version: CONFIG_VERSION,
 				current_profile: None,
 				profiles: Vec::new(),
 				default_developer: None,
-				sdk_nightly: false,
+				sdk_catalog: SdkCatalog::default(),
 				other: HashMap::<String, Value>::new(),
 			};
 		}
@@ -192,32 +626,55 @@ impl Config {
 			info!("Setup Geode using `geode config setup`");
 			// Create new config
 			Config {
+				config_version: CONFIG_VERSION,
 				current_profile: None,
 				profiles: Vec::new(),
 				default_developer: None,
-				sdk_nightly: false,
+				sdk_catalog: SdkCatalog::default(),
 				other: HashMap::<String, Value>::new(),
 			}
 		} else {
-			// Parse config
+			// Parse config, running it through the migration chain if it
+			// predates the current schema version
 			let config_json_str =
 				&std::fs::read_to_string(&config_json).expect("Unable to read config.json");
-			match serde_json::from_str(config_json_str) {
+			let raw: Value = match serde_json::from_str(config_json_str) {
+				Ok(value) => value,
+				Err(e) => {
+					fatal!("Unable to parse config.json: {}", e);
+				}
+			};
+
+			let version = config_version_of(&raw);
+			let raw = if version < CONFIG_VERSION {
+				info!("Migrating config.json to version {}", CONFIG_VERSION);
+				migrate_config(raw, &config_json, config_json_str)
+			} else {
+				raw
+			};
+
+			match serde_json::from_value(raw) {
 				Ok(json) => json,
 				Err(e) => {
-					// Try migrating old config
-					if let Ok(json) = serde_json::from_str::<OldConfig>(config_json_str) {
-						info!("Migrating old config.json");
-						json.migrate()
-					} else {
-						fatal!("Unable to parse config.json: {}", e);
-					}
+					fatal!("Unable to parse migrated config.json: {}", e);
 				}
 			}
 		};
 
 		output.save();
 
+		for profile in &output.profiles {
+			let profile = profile.borrow();
+			if !profile.gd_path.exists() {
+				warn!(
+					"Profile '{}' points to '{}', which no longer exists. It may \
+					have moved; update it using `geode config edit-profile`.",
+					profile.name,
+					profile.gd_path.display()
+				);
+			}
+		}
+
 		if output.profiles.is_empty() {
 			warn!("No Geode profiles found! Some operations will be unavailable.");
 			warn!("Setup Geode using `geode config setup`");
@@ -250,3 +707,126 @@ impl Config {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn baseline_config() -> Value {
+		serde_json::json!({
+			"current-profile": "main",
+			"profiles": [{
+				"name": "main",
+				"gd-path": "/nonexistent/gd",
+			}],
+			"default-developer": null,
+			"sdk-nightly": false,
+		})
+	}
+
+	#[test]
+	fn migrate_v0_to_v1_adds_config_version() {
+		let after = migrate_v0_to_v1(baseline_config());
+		assert_eq!(config_version_of(&after), 1);
+	}
+
+	#[test]
+	fn migrate_v1_to_v2_backfills_platform_on_every_profile() {
+		let after = migrate_v1_to_v2(migrate_v0_to_v1(baseline_config()));
+		let profile = &after["profiles"][0];
+		assert!(
+			profile.get("platform").is_some(),
+			"profile is missing 'platform' after migrating, which fails the final parse"
+		);
+	}
+
+	#[test]
+	fn migrate_v1_to_v2_does_not_relocate_an_already_binary_path() {
+		let mut config = baseline_config();
+		config["profiles"][0]["gd-path"] = Value::from("/nonexistent/GeometryDash.exe");
+		config["profiles"][0]["platform"] = Value::from("win");
+
+		let after = migrate_v1_to_v2(migrate_v0_to_v1(config));
+		assert_eq!(
+			after["profiles"][0]["gd-path"],
+			Value::from("/nonexistent/GeometryDash.exe")
+		);
+	}
+
+	#[test]
+	fn migrate_v2_to_v3_converts_the_nightly_flag_into_a_catalog_selection() {
+		let mut config = baseline_config();
+		config["sdk-nightly"] = Value::from(true);
+
+		let after = migrate_v2_to_v3(migrate_v1_to_v2(migrate_v0_to_v1(config)));
+		assert_eq!(after["sdk-catalog"]["selected"], Value::from("nightly"));
+		assert!(after.get("sdk-nightly").is_none());
+	}
+
+	#[test]
+	fn a_baseline_config_survives_the_full_pipeline_and_parses() {
+		let before = baseline_config();
+		let raw = serde_json::to_string(&before).unwrap();
+		let config_json = std::env::temp_dir().join(format!(
+			"geode-cli-test-config-{:?}.json",
+			std::thread::current().id()
+		));
+
+		let migrated = migrate_config(before, &config_json, &raw);
+		let _ = std::fs::remove_file(config_json.with_extension("json.bak"));
+
+		let config: Config =
+			serde_json::from_value(migrated).expect("a migrated baseline config should parse");
+		assert_eq!(config.profiles[0].borrow().platform, Platform::host());
+	}
+
+	fn legacy_installations_config() -> Value {
+		serde_json::json!({
+			"default-installation": 0,
+			"working-installation": null,
+			"installations": [{
+				"path": "/nonexistent/gd",
+				"executable": "GeometryDash.exe",
+			}],
+			"default-developer": null,
+		})
+	}
+
+	#[test]
+	fn a_legacy_installations_config_survives_the_full_pipeline_and_parses() {
+		let before = legacy_installations_config();
+		let raw = serde_json::to_string(&before).unwrap();
+		let config_json = std::env::temp_dir().join(format!(
+			"geode-cli-test-legacy-config-{:?}.json",
+			std::thread::current().id()
+		));
+
+		let migrated = migrate_config(before, &config_json, &raw);
+		let _ = std::fs::remove_file(config_json.with_extension("json.bak"));
+
+		let config: Config = serde_json::from_value(migrated)
+			.expect("a migrated legacy installations config should parse");
+		assert_eq!(config.profiles.len(), 1);
+		assert_eq!(config.profiles[0].borrow().name, "GeometryDash");
+		assert_eq!(config.profiles[0].borrow().platform, Platform::Win);
+		assert_eq!(config.current_profile.as_deref(), Some("GeometryDash"));
+	}
+
+	#[test]
+	fn next_page_url_finds_the_rel_next_link() {
+		let header = "<https://api.github.com/resource?page=2>; rel=\"next\", \
+			<https://api.github.com/resource?page=5>; rel=\"last\"";
+		assert_eq!(
+			next_page_url(Some(header)),
+			Some("https://api.github.com/resource?page=2".to_owned())
+		);
+	}
+
+	#[test]
+	fn next_page_url_is_none_on_the_last_page() {
+		let header = "<https://api.github.com/resource?page=1>; rel=\"first\", \
+			<https://api.github.com/resource?page=1>; rel=\"prev\"";
+		assert_eq!(next_page_url(Some(header)), None);
+		assert_eq!(next_page_url(None), None);
+	}
+}